@@ -0,0 +1,235 @@
+use std::fmt;
+
+use {DTrs, DVec2, DVec3, Trs, Vec2, Vec3};
+
+/// Single-precision 2D axis-aligned bounding box.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Aabb2 {
+    /// Corner with the smallest coordinate on every axis.
+    pub min: Vec2,
+
+    /// Corner with the largest coordinate on every axis.
+    pub max: Vec2,
+}
+
+/// Double-precision 2D axis-aligned bounding box.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct DAabb2 {
+    /// Corner with the smallest coordinate on every axis.
+    pub min: DVec2,
+
+    /// Corner with the largest coordinate on every axis.
+    pub max: DVec2,
+}
+
+/// Single-precision 3D axis-aligned bounding box.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Aabb3 {
+    /// Corner with the smallest coordinate on every axis.
+    pub min: Vec3,
+
+    /// Corner with the largest coordinate on every axis.
+    pub max: Vec3,
+}
+
+/// Double-precision 3D axis-aligned bounding box.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct DAabb3 {
+    /// Corner with the smallest coordinate on every axis.
+    pub min: DVec3,
+
+    /// Corner with the largest coordinate on every axis.
+    pub max: DVec3,
+}
+
+macro_rules! impl_aabb2 {
+    ($self:ty, $vec:ty) => {
+        impl $self {
+            /// Constructs the box spanned by two points, sorting each axis into
+            /// the `min`/`max` corners.
+            pub fn new(p1: $vec, p2: $vec) -> Self {
+                Self {
+                    min: <$vec>::new(p1.x.min(p2.x), p1.y.min(p2.y)),
+                    max: <$vec>::new(p1.x.max(p2.x), p1.y.max(p2.y)),
+                }
+            }
+
+            /// Returns this box expanded to include `point`.
+            pub fn grow(&self, point: $vec) -> Self {
+                Self {
+                    min: <$vec>::new(self.min.x.min(point.x), self.min.y.min(point.y)),
+                    max: <$vec>::new(self.max.x.max(point.x), self.max.y.max(point.y)),
+                }
+            }
+
+            /// Returns the smallest box containing both boxes.
+            pub fn union(&self, other: &Self) -> Self {
+                Self {
+                    min: <$vec>::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+                    max: <$vec>::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+                }
+            }
+
+            /// Returns `true` if `point` lies within the box (inclusive).
+            pub fn contains(&self, point: $vec) -> bool {
+                point.x >= self.min.x && point.x <= self.max.x
+                    && point.y >= self.min.y && point.y <= self.max.y
+            }
+
+            /// Returns `true` if the two boxes overlap (inclusive).
+            pub fn intersects(&self, other: &Self) -> bool {
+                self.min.x <= other.max.x && self.max.x >= other.min.x
+                    && self.min.y <= other.max.y && self.max.y >= other.min.y
+            }
+
+            /// Returns the centre point of the box.
+            pub fn center(&self) -> $vec {
+                <$vec>::new(
+                    (self.min.x + self.max.x) * 0.5,
+                    (self.min.y + self.max.y) * 0.5,
+                )
+            }
+
+            /// Returns the extent of the box along each axis (`max - min`).
+            pub fn dimensions(&self) -> $vec {
+                self.max - self.min
+            }
+
+            /// Returns the four corners of the box.
+            pub fn corners(&self) -> [$vec; 4] {
+                [
+                    <$vec>::new(self.min.x, self.min.y),
+                    <$vec>::new(self.max.x, self.min.y),
+                    <$vec>::new(self.min.x, self.max.y),
+                    <$vec>::new(self.max.x, self.max.y),
+                ]
+            }
+        }
+
+        impl fmt::Display for $self {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{:?}", (self.min, self.max))
+            }
+        }
+    };
+}
+
+macro_rules! impl_aabb3 {
+    ($self:ty, $vec:ty, $trs:ty) => {
+        impl $self {
+            /// Constructs the box spanned by two points, sorting each axis into
+            /// the `min`/`max` corners.
+            pub fn new(p1: $vec, p2: $vec) -> Self {
+                Self {
+                    min: <$vec>::new(p1.x.min(p2.x), p1.y.min(p2.y), p1.z.min(p2.z)),
+                    max: <$vec>::new(p1.x.max(p2.x), p1.y.max(p2.y), p1.z.max(p2.z)),
+                }
+            }
+
+            /// Returns this box expanded to include `point`.
+            pub fn grow(&self, point: $vec) -> Self {
+                Self {
+                    min: <$vec>::new(
+                        self.min.x.min(point.x),
+                        self.min.y.min(point.y),
+                        self.min.z.min(point.z),
+                    ),
+                    max: <$vec>::new(
+                        self.max.x.max(point.x),
+                        self.max.y.max(point.y),
+                        self.max.z.max(point.z),
+                    ),
+                }
+            }
+
+            /// Returns the smallest box containing both boxes.
+            pub fn union(&self, other: &Self) -> Self {
+                Self {
+                    min: <$vec>::new(
+                        self.min.x.min(other.min.x),
+                        self.min.y.min(other.min.y),
+                        self.min.z.min(other.min.z),
+                    ),
+                    max: <$vec>::new(
+                        self.max.x.max(other.max.x),
+                        self.max.y.max(other.max.y),
+                        self.max.z.max(other.max.z),
+                    ),
+                }
+            }
+
+            /// Returns `true` if `point` lies within the box (inclusive).
+            pub fn contains(&self, point: $vec) -> bool {
+                point.x >= self.min.x && point.x <= self.max.x
+                    && point.y >= self.min.y && point.y <= self.max.y
+                    && point.z >= self.min.z && point.z <= self.max.z
+            }
+
+            /// Returns `true` if the two boxes overlap (inclusive).
+            pub fn intersects(&self, other: &Self) -> bool {
+                self.min.x <= other.max.x && self.max.x >= other.min.x
+                    && self.min.y <= other.max.y && self.max.y >= other.min.y
+                    && self.min.z <= other.max.z && self.max.z >= other.min.z
+            }
+
+            /// Returns the centre point of the box.
+            pub fn center(&self) -> $vec {
+                <$vec>::new(
+                    (self.min.x + self.max.x) * 0.5,
+                    (self.min.y + self.max.y) * 0.5,
+                    (self.min.z + self.max.z) * 0.5,
+                )
+            }
+
+            /// Returns the extent of the box along each axis (`max - min`).
+            pub fn dimensions(&self) -> $vec {
+                self.max - self.min
+            }
+
+            /// Returns the eight corners of the box.
+            pub fn corners(&self) -> [$vec; 8] {
+                [
+                    <$vec>::new(self.min.x, self.min.y, self.min.z),
+                    <$vec>::new(self.max.x, self.min.y, self.min.z),
+                    <$vec>::new(self.min.x, self.max.y, self.min.z),
+                    <$vec>::new(self.max.x, self.max.y, self.min.z),
+                    <$vec>::new(self.min.x, self.min.y, self.max.z),
+                    <$vec>::new(self.max.x, self.min.y, self.max.z),
+                    <$vec>::new(self.min.x, self.max.y, self.max.z),
+                    <$vec>::new(self.max.x, self.max.y, self.max.z),
+                ]
+            }
+
+            /// Returns the tight box enclosing this one after applying a
+            /// transform, computed by transforming all eight corners and
+            /// re-taking the component-wise minimum and maximum.
+            pub fn transform(&self, trs: &$trs) -> Self {
+                let corners = self.corners();
+                let mut min = trs.transform_point(corners[0]);
+                let mut max = min;
+                for corner in corners.iter().skip(1) {
+                    let p = trs.transform_point(*corner);
+                    min = <$vec>::new(min.x.min(p.x), min.y.min(p.y), min.z.min(p.z));
+                    max = <$vec>::new(max.x.max(p.x), max.y.max(p.y), max.z.max(p.z));
+                }
+                Self { min, max }
+            }
+        }
+
+        impl fmt::Display for $self {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{:?}", (self.min, self.max))
+            }
+        }
+    };
+}
+
+impl_aabb2!(Aabb2, Vec2);
+impl_aabb2!(DAabb2, DVec2);
+
+impl_aabb3!(Aabb3, Vec3, Trs);
+impl_aabb3!(DAabb3, DVec3, DTrs);
@@ -1,11 +1,18 @@
 use cgmath;
 use std::fmt;
+use std::ops;
 
 use approx::{AbsDiffEq,RelativeEq,UlpsEq};
 use {DQuat, DMat4, DVec3, Quat, Mat4, Vec3};
 
+#[cfg(feature = "mint")]
+use mint;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Single-precision translation + rotation + non-uniform scale transform.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Trs {
     /// Translation vector.
     pub t: Vec3,
@@ -52,6 +59,257 @@ impl Trs {
         let m: [[f32; 4]; 4] = (t * r * s).into();
         Mat4::from(m)
     }
+
+    /// Re-decomposes a matrix into its translation, rotation and scale parts.
+    ///
+    /// This is the inverse path of [`Trs::matrix`] and is used to consume
+    /// transforms stored as a single matrix (e.g. a glTF node's `matrix`).
+    /// Translation is the 4th column's `xyz`; each upper-left basis column's
+    /// length gives a scale factor, with one component negated (conventionally
+    /// `s.x`) when the 3×3 determinant is negative so the remaining rotation is
+    /// proper; the normalised basis is converted to a quaternion via the trace
+    /// method. A near-zero basis column falls back to the corresponding
+    /// identity axis.
+    pub fn from_matrix(matrix: Mat4) -> Trs {
+        let m: [[f32; 4]; 4] = matrix.into();
+        let t = Vec3::new(m[3][0], m[3][1], m[3][2]);
+
+        let mut cx = Vec3::new(m[0][0], m[0][1], m[0][2]);
+        let mut cy = Vec3::new(m[1][0], m[1][1], m[1][2]);
+        let mut cz = Vec3::new(m[2][0], m[2][1], m[2][2]);
+
+        let mut sx = (cx.x * cx.x + cx.y * cx.y + cx.z * cx.z).sqrt();
+        let sy = (cy.x * cy.x + cy.y * cy.y + cy.z * cy.z).sqrt();
+        let sz = (cz.x * cz.x + cz.y * cz.y + cz.z * cz.z).sqrt();
+
+        let det = cx.x * (cy.y * cz.z - cy.z * cz.y)
+            - cx.y * (cy.x * cz.z - cy.z * cz.x)
+            + cx.z * (cy.x * cz.y - cy.y * cz.x);
+        if det < 0.0 {
+            sx = -sx;
+        }
+
+        if sx.abs() > ::std::f32::EPSILON {
+            cx = Vec3::new(cx.x / sx, cx.y / sx, cx.z / sx);
+        } else {
+            cx = Vec3::new(1.0, 0.0, 0.0);
+        }
+        if sy > ::std::f32::EPSILON {
+            cy = Vec3::new(cy.x / sy, cy.y / sy, cy.z / sy);
+        } else {
+            cy = Vec3::new(0.0, 1.0, 0.0);
+        }
+        if sz > ::std::f32::EPSILON {
+            cz = Vec3::new(cz.x / sz, cz.y / sz, cz.z / sz);
+        } else {
+            cz = Vec3::new(0.0, 0.0, 1.0);
+        }
+
+        let r = rotation_from_basis(cx, cy, cz);
+        Trs::new(t, r, Vec3::new(sx, sy, sz))
+    }
+
+    /// Returns the inverse of this transform.
+    ///
+    /// The inverse scale is `1/s`, the inverse rotation is the conjugate
+    /// `r*`, and the inverse translation is `-(r⁻¹ * (s⁻¹ ⊙ t))`.
+    pub fn inverse(&self) -> Trs {
+        let inv_s = Vec3::new(1.0 / self.s.x, 1.0 / self.s.y, 1.0 / self.s.z);
+        let inv_r = quat_conjugate(self.r);
+        let scaled = Vec3::new(inv_s.x * self.t.x, inv_s.y * self.t.y, inv_s.z * self.t.z);
+        let rotated = quat_rotate(inv_r, scaled);
+        let t = Vec3::new(-rotated.x, -rotated.y, -rotated.z);
+        Trs::new(t, inv_r, inv_s)
+    }
+
+    /// Applies this transform to a point, computing `t + r * (s ⊙ p)`.
+    pub fn transform_point(&self, p: Vec3) -> Vec3 {
+        let scaled = Vec3::new(self.s.x * p.x, self.s.y * p.y, self.s.z * p.z);
+        self.t + quat_rotate(self.r, scaled)
+    }
+
+    /// Applies this transform to a direction vector, computing `r * (s ⊙ v)`.
+    ///
+    /// The translation is ignored. Note that this is the rotation-and-scale of
+    /// the point transform, not the inverse-transpose used for normals.
+    pub fn transform_vector(&self, v: Vec3) -> Vec3 {
+        let scaled = Vec3::new(self.s.x * v.x, self.s.y * v.y, self.s.z * v.z);
+        quat_rotate(self.r, scaled)
+    }
+
+    /// Interpolates between two transforms by the fraction `t` in `[0, 1]`.
+    ///
+    /// Translation and scale are interpolated component-wise and the rotation
+    /// with normalised lerp along the shortest arc, which is the cheap default
+    /// suitable for sampling dense animation curves. See
+    /// [`Trs::interpolate_slerp`] for constant-velocity interpolation.
+    pub fn interpolate(&self, other: &Trs, t: f32) -> Trs {
+        Trs::new(
+            vec3_lerp(self.t, other.t, t),
+            quat_nlerp(self.r, other.r, t),
+            vec3_lerp(self.s, other.s, t),
+        )
+    }
+
+    /// Interpolates between two transforms like [`Trs::interpolate`], but uses
+    /// spherical linear interpolation for the rotation.
+    ///
+    /// This keeps a constant angular velocity across the arc. When the two
+    /// rotations are nearly parallel (dot product above `0.9995`) it falls back
+    /// to normalised lerp to avoid dividing by a vanishing sine.
+    pub fn interpolate_slerp(&self, other: &Trs, t: f32) -> Trs {
+        Trs::new(
+            vec3_lerp(self.t, other.t, t),
+            quat_slerp(self.r, other.r, t),
+            vec3_lerp(self.s, other.s, t),
+        )
+    }
+}
+
+/// Component-wise linear interpolation of two single-precision vectors.
+fn vec3_lerp(a: Vec3, b: Vec3, t: f32) -> Vec3 {
+    let u = 1.0 - t;
+    Vec3::new(a.x * u + b.x * t, a.y * u + b.y * t, a.z * u + b.z * t)
+}
+
+/// Dot product of two single-precision quaternions.
+fn quat_dot(a: Quat, b: Quat) -> f32 {
+    a.s * b.s + a.x * b.x + a.y * b.y + a.z * b.z
+}
+
+/// Negates every component of a single-precision quaternion.
+fn quat_neg(q: Quat) -> Quat {
+    Quat { s: -q.s, x: -q.x, y: -q.y, z: -q.z }
+}
+
+/// Normalises a single-precision quaternion, returning it unchanged if its
+/// magnitude is zero.
+fn quat_normalize(q: Quat) -> Quat {
+    let m = quat_dot(q, q).sqrt();
+    if m == 0.0 {
+        q
+    } else {
+        Quat { s: q.s / m, x: q.x / m, y: q.y / m, z: q.z / m }
+    }
+}
+
+/// Normalised lerp of two single-precision quaternions along the shortest arc.
+fn quat_nlerp(a: Quat, b: Quat, t: f32) -> Quat {
+    let b = if quat_dot(a, b) < 0.0 { quat_neg(b) } else { b };
+    let u = 1.0 - t;
+    quat_normalize(Quat {
+        s: a.s * u + b.s * t,
+        x: a.x * u + b.x * t,
+        y: a.y * u + b.y * t,
+        z: a.z * u + b.z * t,
+    })
+}
+
+/// Spherical lerp of two single-precision quaternions along the shortest arc,
+/// falling back to [`quat_nlerp`] when the rotations are nearly parallel.
+fn quat_slerp(a: Quat, b: Quat, t: f32) -> Quat {
+    let mut d = quat_dot(a, b);
+    let b = if d < 0.0 { d = -d; quat_neg(b) } else { b };
+    if d > 0.9995 {
+        return quat_nlerp(a, b, t);
+    }
+    let theta = d.acos();
+    let sin_theta = theta.sin();
+    let w0 = ((1.0 - t) * theta).sin() / sin_theta;
+    let w1 = (t * theta).sin() / sin_theta;
+    Quat {
+        s: a.s * w0 + b.s * w1,
+        x: a.x * w0 + b.x * w1,
+        y: a.y * w0 + b.y * w1,
+        z: a.z * w0 + b.z * w1,
+    }
+}
+
+/// Returns the conjugate of a single-precision quaternion.
+fn quat_conjugate(q: Quat) -> Quat {
+    let c = cgmath::Quaternion::new(q.s, q.x, q.y, q.z).conjugate();
+    Quat { s: c.s, x: c.v.x, y: c.v.y, z: c.v.z }
+}
+
+/// Multiplies two single-precision quaternions (`a` then applied outside `b`).
+fn quat_mul(a: Quat, b: Quat) -> Quat {
+    let p = cgmath::Quaternion::new(a.s, a.x, a.y, a.z);
+    let q = cgmath::Quaternion::new(b.s, b.x, b.y, b.z);
+    let r = p * q;
+    Quat { s: r.s, x: r.v.x, y: r.v.y, z: r.v.z }
+}
+
+/// Rotates a single-precision vector by a quaternion.
+fn quat_rotate(q: Quat, v: Vec3) -> Vec3 {
+    let r = cgmath::Quaternion::new(q.s, q.x, q.y, q.z)
+        * cgmath::Vector3::new(v.x, v.y, v.z);
+    Vec3::new(r.x, r.y, r.z)
+}
+
+impl ops::Mul<Trs> for Trs {
+    type Output = Trs;
+
+    /// Composes two transforms. The result applied to a point is equivalent
+    /// to applying `rhs` first and then `self` (`self` is the parent node).
+    ///
+    /// For the common case of a uniform parent scale the composition is
+    /// computed directly in TRS space as `t = a.t + a.r * (a.s ⊙ b.t)`,
+    /// `r = a.r * b.r` and `s = a.s ⊙ b.s`. When the parent scale is
+    /// non-uniform rotation and scale no longer commute, so the transforms
+    /// are multiplied as matrices and the product re-decomposed into a `Trs`.
+    fn mul(self, rhs: Trs) -> Trs {
+        let a = self;
+        let b = rhs;
+        if a.s.x == a.s.y && a.s.y == a.s.z {
+            let st = Vec3::new(a.s.x * b.t.x, a.s.y * b.t.y, a.s.z * b.t.z);
+            let t = a.t + quat_rotate(a.r, st);
+            let r = quat_mul(a.r, b.r);
+            let s = Vec3::new(a.s.x * b.s.x, a.s.y * b.s.y, a.s.z * b.s.z);
+            Trs::new(t, r, s)
+        } else {
+            Trs::from_matrix(a.matrix() * b.matrix())
+        }
+    }
+}
+
+/// Converts an orthonormal basis (given as its three column vectors) to a
+/// quaternion using the standard trace method.
+fn rotation_from_basis(cx: Vec3, cy: Vec3, cz: Vec3) -> Quat {
+    let trace = cx.x + cy.y + cz.z;
+    if trace > 0.0 {
+        let w = (1.0 + trace).sqrt() * 0.5;
+        let inv = 0.25 / w;
+        Quat {
+            s: w,
+            x: (cy.z - cz.y) * inv,
+            y: (cz.x - cx.z) * inv,
+            z: (cx.y - cy.x) * inv,
+        }
+    } else if cx.x > cy.y && cx.x > cz.z {
+        let d = (1.0 + cx.x - cy.y - cz.z).sqrt() * 2.0;
+        Quat {
+            s: (cy.z - cz.y) / d,
+            x: 0.25 * d,
+            y: (cy.x + cx.y) / d,
+            z: (cz.x + cx.z) / d,
+        }
+    } else if cy.y > cz.z {
+        let d = (1.0 + cy.y - cx.x - cz.z).sqrt() * 2.0;
+        Quat {
+            s: (cz.x - cx.z) / d,
+            x: (cy.x + cx.y) / d,
+            y: 0.25 * d,
+            z: (cz.y + cy.z) / d,
+        }
+    } else {
+        let d = (1.0 + cz.z - cx.x - cy.y).sqrt() * 2.0;
+        Quat {
+            s: (cx.y - cy.x) / d,
+            x: (cz.x + cx.z) / d,
+            y: (cz.y + cy.z) / d,
+            z: 0.25 * d,
+        }
+    }
 }
 
 impl RelativeEq for Trs {
@@ -114,6 +372,7 @@ impl AbsDiffEq for Trs {
 
 /// Double-precision translation + rotation + non-uniform scale transform.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DTrs {
     /// Translation vector.
     pub t: DVec3,
@@ -160,6 +419,257 @@ impl DTrs {
         let m: [[f64; 4]; 4] = (t * r * s).into();
         DMat4::from(m)
     }
+
+    /// Re-decomposes a matrix into its translation, rotation and scale parts.
+    ///
+    /// This is the inverse path of [`DTrs::matrix`] and is used to consume
+    /// transforms stored as a single matrix (e.g. a glTF node's `matrix`).
+    /// Translation is the 4th column's `xyz`; each upper-left basis column's
+    /// length gives a scale factor, with one component negated (conventionally
+    /// `s.x`) when the 3×3 determinant is negative so the remaining rotation is
+    /// proper; the normalised basis is converted to a quaternion via the trace
+    /// method. A near-zero basis column falls back to the corresponding
+    /// identity axis.
+    pub fn from_matrix(matrix: DMat4) -> DTrs {
+        let m: [[f64; 4]; 4] = matrix.into();
+        let t = DVec3::new(m[3][0], m[3][1], m[3][2]);
+
+        let mut cx = DVec3::new(m[0][0], m[0][1], m[0][2]);
+        let mut cy = DVec3::new(m[1][0], m[1][1], m[1][2]);
+        let mut cz = DVec3::new(m[2][0], m[2][1], m[2][2]);
+
+        let mut sx = (cx.x * cx.x + cx.y * cx.y + cx.z * cx.z).sqrt();
+        let sy = (cy.x * cy.x + cy.y * cy.y + cy.z * cy.z).sqrt();
+        let sz = (cz.x * cz.x + cz.y * cz.y + cz.z * cz.z).sqrt();
+
+        let det = cx.x * (cy.y * cz.z - cy.z * cz.y)
+            - cx.y * (cy.x * cz.z - cy.z * cz.x)
+            + cx.z * (cy.x * cz.y - cy.y * cz.x);
+        if det < 0.0 {
+            sx = -sx;
+        }
+
+        if sx.abs() > ::std::f64::EPSILON {
+            cx = DVec3::new(cx.x / sx, cx.y / sx, cx.z / sx);
+        } else {
+            cx = DVec3::new(1.0, 0.0, 0.0);
+        }
+        if sy > ::std::f64::EPSILON {
+            cy = DVec3::new(cy.x / sy, cy.y / sy, cy.z / sy);
+        } else {
+            cy = DVec3::new(0.0, 1.0, 0.0);
+        }
+        if sz > ::std::f64::EPSILON {
+            cz = DVec3::new(cz.x / sz, cz.y / sz, cz.z / sz);
+        } else {
+            cz = DVec3::new(0.0, 0.0, 1.0);
+        }
+
+        let r = drotation_from_basis(cx, cy, cz);
+        DTrs::new(t, r, DVec3::new(sx, sy, sz))
+    }
+
+    /// Returns the inverse of this transform.
+    ///
+    /// The inverse scale is `1/s`, the inverse rotation is the conjugate
+    /// `r*`, and the inverse translation is `-(r⁻¹ * (s⁻¹ ⊙ t))`.
+    pub fn inverse(&self) -> DTrs {
+        let inv_s = DVec3::new(1.0 / self.s.x, 1.0 / self.s.y, 1.0 / self.s.z);
+        let inv_r = dquat_conjugate(self.r);
+        let scaled = DVec3::new(inv_s.x * self.t.x, inv_s.y * self.t.y, inv_s.z * self.t.z);
+        let rotated = dquat_rotate(inv_r, scaled);
+        let t = DVec3::new(-rotated.x, -rotated.y, -rotated.z);
+        DTrs::new(t, inv_r, inv_s)
+    }
+
+    /// Applies this transform to a point, computing `t + r * (s ⊙ p)`.
+    pub fn transform_point(&self, p: DVec3) -> DVec3 {
+        let scaled = DVec3::new(self.s.x * p.x, self.s.y * p.y, self.s.z * p.z);
+        self.t + dquat_rotate(self.r, scaled)
+    }
+
+    /// Applies this transform to a direction vector, computing `r * (s ⊙ v)`.
+    ///
+    /// The translation is ignored. Note that this is the rotation-and-scale of
+    /// the point transform, not the inverse-transpose used for normals.
+    pub fn transform_vector(&self, v: DVec3) -> DVec3 {
+        let scaled = DVec3::new(self.s.x * v.x, self.s.y * v.y, self.s.z * v.z);
+        dquat_rotate(self.r, scaled)
+    }
+
+    /// Interpolates between two transforms by the fraction `t` in `[0, 1]`.
+    ///
+    /// Translation and scale are interpolated component-wise and the rotation
+    /// with normalised lerp along the shortest arc, which is the cheap default
+    /// suitable for sampling dense animation curves. See
+    /// [`DTrs::interpolate_slerp`] for constant-velocity interpolation.
+    pub fn interpolate(&self, other: &DTrs, t: f64) -> DTrs {
+        DTrs::new(
+            dvec3_lerp(self.t, other.t, t),
+            dquat_nlerp(self.r, other.r, t),
+            dvec3_lerp(self.s, other.s, t),
+        )
+    }
+
+    /// Interpolates between two transforms like [`DTrs::interpolate`], but uses
+    /// spherical linear interpolation for the rotation.
+    ///
+    /// This keeps a constant angular velocity across the arc. When the two
+    /// rotations are nearly parallel (dot product above `0.9995`) it falls back
+    /// to normalised lerp to avoid dividing by a vanishing sine.
+    pub fn interpolate_slerp(&self, other: &DTrs, t: f64) -> DTrs {
+        DTrs::new(
+            dvec3_lerp(self.t, other.t, t),
+            dquat_slerp(self.r, other.r, t),
+            dvec3_lerp(self.s, other.s, t),
+        )
+    }
+}
+
+/// Component-wise linear interpolation of two double-precision vectors.
+fn dvec3_lerp(a: DVec3, b: DVec3, t: f64) -> DVec3 {
+    let u = 1.0 - t;
+    DVec3::new(a.x * u + b.x * t, a.y * u + b.y * t, a.z * u + b.z * t)
+}
+
+/// Dot product of two double-precision quaternions.
+fn dquat_dot(a: DQuat, b: DQuat) -> f64 {
+    a.s * b.s + a.x * b.x + a.y * b.y + a.z * b.z
+}
+
+/// Negates every component of a double-precision quaternion.
+fn dquat_neg(q: DQuat) -> DQuat {
+    DQuat { s: -q.s, x: -q.x, y: -q.y, z: -q.z }
+}
+
+/// Normalises a double-precision quaternion, returning it unchanged if its
+/// magnitude is zero.
+fn dquat_normalize(q: DQuat) -> DQuat {
+    let m = dquat_dot(q, q).sqrt();
+    if m == 0.0 {
+        q
+    } else {
+        DQuat { s: q.s / m, x: q.x / m, y: q.y / m, z: q.z / m }
+    }
+}
+
+/// Normalised lerp of two double-precision quaternions along the shortest arc.
+fn dquat_nlerp(a: DQuat, b: DQuat, t: f64) -> DQuat {
+    let b = if dquat_dot(a, b) < 0.0 { dquat_neg(b) } else { b };
+    let u = 1.0 - t;
+    dquat_normalize(DQuat {
+        s: a.s * u + b.s * t,
+        x: a.x * u + b.x * t,
+        y: a.y * u + b.y * t,
+        z: a.z * u + b.z * t,
+    })
+}
+
+/// Spherical lerp of two double-precision quaternions along the shortest arc,
+/// falling back to [`dquat_nlerp`] when the rotations are nearly parallel.
+fn dquat_slerp(a: DQuat, b: DQuat, t: f64) -> DQuat {
+    let mut d = dquat_dot(a, b);
+    let b = if d < 0.0 { d = -d; dquat_neg(b) } else { b };
+    if d > 0.9995 {
+        return dquat_nlerp(a, b, t);
+    }
+    let theta = d.acos();
+    let sin_theta = theta.sin();
+    let w0 = ((1.0 - t) * theta).sin() / sin_theta;
+    let w1 = (t * theta).sin() / sin_theta;
+    DQuat {
+        s: a.s * w0 + b.s * w1,
+        x: a.x * w0 + b.x * w1,
+        y: a.y * w0 + b.y * w1,
+        z: a.z * w0 + b.z * w1,
+    }
+}
+
+/// Returns the conjugate of a double-precision quaternion.
+fn dquat_conjugate(q: DQuat) -> DQuat {
+    let c = cgmath::Quaternion::new(q.s, q.x, q.y, q.z).conjugate();
+    DQuat { s: c.s, x: c.v.x, y: c.v.y, z: c.v.z }
+}
+
+/// Multiplies two double-precision quaternions.
+fn dquat_mul(a: DQuat, b: DQuat) -> DQuat {
+    let p = cgmath::Quaternion::new(a.s, a.x, a.y, a.z);
+    let q = cgmath::Quaternion::new(b.s, b.x, b.y, b.z);
+    let r = p * q;
+    DQuat { s: r.s, x: r.v.x, y: r.v.y, z: r.v.z }
+}
+
+/// Rotates a double-precision vector by a quaternion.
+fn dquat_rotate(q: DQuat, v: DVec3) -> DVec3 {
+    let r = cgmath::Quaternion::new(q.s, q.x, q.y, q.z)
+        * cgmath::Vector3::new(v.x, v.y, v.z);
+    DVec3::new(r.x, r.y, r.z)
+}
+
+impl ops::Mul<DTrs> for DTrs {
+    type Output = DTrs;
+
+    /// Composes two transforms. The result applied to a point is equivalent
+    /// to applying `rhs` first and then `self` (`self` is the parent node).
+    ///
+    /// For the common case of a uniform parent scale the composition is
+    /// computed directly in TRS space as `t = a.t + a.r * (a.s ⊙ b.t)`,
+    /// `r = a.r * b.r` and `s = a.s ⊙ b.s`. When the parent scale is
+    /// non-uniform rotation and scale no longer commute, so the transforms
+    /// are multiplied as matrices and the product re-decomposed into a `DTrs`.
+    fn mul(self, rhs: DTrs) -> DTrs {
+        let a = self;
+        let b = rhs;
+        if a.s.x == a.s.y && a.s.y == a.s.z {
+            let st = DVec3::new(a.s.x * b.t.x, a.s.y * b.t.y, a.s.z * b.t.z);
+            let t = a.t + dquat_rotate(a.r, st);
+            let r = dquat_mul(a.r, b.r);
+            let s = DVec3::new(a.s.x * b.s.x, a.s.y * b.s.y, a.s.z * b.s.z);
+            DTrs::new(t, r, s)
+        } else {
+            DTrs::from_matrix(a.matrix() * b.matrix())
+        }
+    }
+}
+
+/// Converts an orthonormal basis (given as its three column vectors) to a
+/// quaternion using the standard trace method.
+fn drotation_from_basis(cx: DVec3, cy: DVec3, cz: DVec3) -> DQuat {
+    let trace = cx.x + cy.y + cz.z;
+    if trace > 0.0 {
+        let w = (1.0 + trace).sqrt() * 0.5;
+        let inv = 0.25 / w;
+        DQuat {
+            s: w,
+            x: (cy.z - cz.y) * inv,
+            y: (cz.x - cx.z) * inv,
+            z: (cx.y - cy.x) * inv,
+        }
+    } else if cx.x > cy.y && cx.x > cz.z {
+        let d = (1.0 + cx.x - cy.y - cz.z).sqrt() * 2.0;
+        DQuat {
+            s: (cy.z - cz.y) / d,
+            x: 0.25 * d,
+            y: (cy.x + cx.y) / d,
+            z: (cz.x + cx.z) / d,
+        }
+    } else if cy.y > cz.z {
+        let d = (1.0 + cy.y - cx.x - cz.z).sqrt() * 2.0;
+        DQuat {
+            s: (cz.x - cx.z) / d,
+            x: (cy.x + cx.y) / d,
+            y: 0.25 * d,
+            z: (cz.y + cy.z) / d,
+        }
+    } else {
+        let d = (1.0 + cz.z - cx.x - cy.y).sqrt() * 2.0;
+        DQuat {
+            s: (cx.y - cy.x) / d,
+            x: (cz.x + cx.z) / d,
+            y: (cz.y + cy.z) / d,
+            z: 0.25 * d,
+        }
+    }
 }
 
 impl RelativeEq for DTrs {
@@ -219,3 +729,135 @@ impl AbsDiffEq for DTrs {
         self.s.abs_diff_eq(&other.s, epsilon, max_ulps)
     }
 }
+
+// Serde and mint interop for the quaternion and matrix types. The vector
+// types carry their own array-based serde and mint impls, and `Trs`/`DTrs`
+// derive serde on top of these; `Trs`/`DTrs` have no mint conversion.
+
+#[cfg(feature = "serde")]
+impl Serialize for Quat {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        [self.x, self.y, self.z, self.s].serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Quat {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [x, y, z, s] = <[f32; 4]>::deserialize(deserializer)?;
+        Ok(Quat { x, y, z, s })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for DQuat {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        [self.x, self.y, self.z, self.s].serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for DQuat {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [x, y, z, s] = <[f64; 4]>::deserialize(deserializer)?;
+        Ok(DQuat { x, y, z, s })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Mat4 {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let m: [[f32; 4]; 4] = (*self).into();
+        m.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Mat4 {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let m = <[[f32; 4]; 4]>::deserialize(deserializer)?;
+        Ok(Mat4::from(m))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for DMat4 {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let m: [[f64; 4]; 4] = (*self).into();
+        m.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for DMat4 {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let m = <[[f64; 4]; 4]>::deserialize(deserializer)?;
+        Ok(DMat4::from(m))
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Quaternion<f32>> for Quat {
+    fn from(q: mint::Quaternion<f32>) -> Self {
+        Quat { x: q.v.x, y: q.v.y, z: q.v.z, s: q.s }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Quat> for mint::Quaternion<f32> {
+    fn from(q: Quat) -> Self {
+        mint::Quaternion {
+            s: q.s,
+            v: mint::Vector3 { x: q.x, y: q.y, z: q.z },
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Quaternion<f64>> for DQuat {
+    fn from(q: mint::Quaternion<f64>) -> Self {
+        DQuat { x: q.v.x, y: q.v.y, z: q.v.z, s: q.s }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<DQuat> for mint::Quaternion<f64> {
+    fn from(q: DQuat) -> Self {
+        mint::Quaternion {
+            s: q.s,
+            v: mint::Vector3 { x: q.x, y: q.y, z: q.z },
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::ColumnMatrix4<f32>> for Mat4 {
+    fn from(m: mint::ColumnMatrix4<f32>) -> Self {
+        let a: [[f32; 4]; 4] = m.into();
+        Mat4::from(a)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Mat4> for mint::ColumnMatrix4<f32> {
+    fn from(m: Mat4) -> Self {
+        let a: [[f32; 4]; 4] = m.into();
+        a.into()
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::ColumnMatrix4<f64>> for DMat4 {
+    fn from(m: mint::ColumnMatrix4<f64>) -> Self {
+        let a: [[f64; 4]; 4] = m.into();
+        DMat4::from(a)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<DMat4> for mint::ColumnMatrix4<f64> {
+    fn from(m: DMat4) -> Self {
+        let a: [[f64; 4]; 4] = m.into();
+        a.into()
+    }
+}
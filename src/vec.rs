@@ -2,8 +2,15 @@ use approx::ApproxEq;
 use cgmath;
 use std::{fmt, mem, ops};
 
+#[cfg(feature = "mint")]
+use mint;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Single-precision 2D vector.
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "[f32; 2]", from = "[f32; 2]"))]
 #[repr(C)]
 pub struct Vec2 {
     pub x: f32,
@@ -48,6 +55,8 @@ impl fmt::Display for Vec2 {
 
 /// Single-precision 3D vector.
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "[f32; 3]", from = "[f32; 3]"))]
 #[repr(C)]
 pub struct Vec3 {
     pub x: f32,
@@ -99,6 +108,8 @@ impl fmt::Display for Vec3 {
 
 /// Single-precision 4D vector.
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "[f32; 4]", from = "[f32; 4]"))]
 #[repr(C)]
 pub struct Vec4 {
     pub x: f32,
@@ -157,6 +168,8 @@ impl fmt::Display for Vec4 {
 
 /// Double-precision 2D vector.
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "[f64; 2]", from = "[f64; 2]"))]
 #[repr(C)]
 pub struct DVec2 {
     pub x: f64,
@@ -201,6 +214,8 @@ impl fmt::Display for DVec2 {
 
 /// Double-precision 3D vector.
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "[f64; 3]", from = "[f64; 3]"))]
 #[repr(C)]
 pub struct DVec3 {
     pub x: f64,
@@ -252,6 +267,8 @@ impl fmt::Display for DVec3 {
 
 /// Double-precision 4D vector.
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "[f64; 4]", from = "[f64; 4]"))]
 #[repr(C)]
 pub struct DVec4 {
     pub x: f64,
@@ -338,6 +355,61 @@ macro_rules! impl_vector {
                 let b: &$inner = rhs.as_ref().into();
                 a.dot(*b)
             }
+
+            /// Returns the squared length of the vector.
+            pub fn magnitude2(self) -> $base {
+                use cgmath::InnerSpace;
+                let a: &$inner = self.as_ref().into();
+                a.magnitude2()
+            }
+
+            /// Returns the length of the vector.
+            pub fn magnitude(self) -> $base {
+                use cgmath::InnerSpace;
+                let a: &$inner = self.as_ref().into();
+                a.magnitude()
+            }
+
+            /// Returns the vector normalized to unit length.
+            pub fn normalize(self) -> $self {
+                use cgmath::InnerSpace;
+                let a: &$inner = self.as_ref().into();
+                let v: $array = a.normalize().into();
+                v.into()
+            }
+
+            /// Returns the vector normalized to the given length.
+            pub fn normalize_to(self, len: $base) -> $self {
+                use cgmath::InnerSpace;
+                let a: &$inner = self.as_ref().into();
+                let v: $array = a.normalize_to(len).into();
+                v.into()
+            }
+
+            /// Returns the Euclidean distance to another vector.
+            pub fn distance(self, rhs: $self) -> $base {
+                (self - rhs).magnitude()
+            }
+
+            /// Returns the angle between two vectors, in radians.
+            pub fn angle(self, rhs: $self) -> $base {
+                use cgmath::InnerSpace;
+                let a: &$inner = self.as_ref().into();
+                let b: &$inner = rhs.as_ref().into();
+                a.angle(*b).0
+            }
+
+            /// Returns the projection of this vector onto another,
+            /// `other * (self.dot(other) / other.dot(other))`.
+            pub fn project_on(self, other: $self) -> $self {
+                other * (self.dot(other) / other.dot(other))
+            }
+
+            /// Returns this vector reflected about the given normal,
+            /// `self - normal * (2 * self.dot(normal))`.
+            pub fn reflect(self, normal: $self) -> $self {
+                self - normal * (self.dot(normal) * 2.0)
+            }
         }
 
         impl ops::Add<$self> for $self {
@@ -440,3 +512,391 @@ impl_vector!(Vec4, f32, cgmath::Vector4<f32>, [f32; 4]);
 impl_vector!(DVec2, f64, cgmath::Vector2<f64>, [f64; 2]);
 impl_vector!(DVec3, f64, cgmath::Vector3<f64>, [f64; 3]);
 impl_vector!(DVec4, f64, cgmath::Vector4<f64>, [f64; 4]);
+
+/// Signed 32-bit integer 2D vector.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct IVec2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl IVec2 {
+    /// Full constructor.
+    pub fn new(x: i32, y: i32) -> Self {
+        IVec2 { x, y }
+    }
+
+    /// Zero constructor.
+    pub fn zero() -> Self {
+        Default::default()
+    }
+}
+
+impl fmt::Display for IVec2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", (self.x, self.y))
+    }
+}
+
+/// Signed 32-bit integer 3D vector.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct IVec3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl IVec3 {
+    /// Full constructor.
+    pub fn new(x: i32, y: i32, z: i32) -> Self {
+        IVec3 { x, y, z }
+    }
+
+    /// Zero constructor.
+    pub fn zero() -> Self {
+        Default::default()
+    }
+}
+
+impl fmt::Display for IVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", (self.x, self.y, self.z))
+    }
+}
+
+/// Signed 32-bit integer 4D vector.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct IVec4 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+    pub w: i32,
+}
+
+impl IVec4 {
+    /// Full constructor.
+    pub fn new(x: i32, y: i32, z: i32, w: i32) -> Self {
+        IVec4 { x, y, z, w }
+    }
+
+    /// Zero constructor.
+    pub fn zero() -> Self {
+        Default::default()
+    }
+}
+
+impl fmt::Display for IVec4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", (self.x, self.y, self.z, self.w))
+    }
+}
+
+/// Unsigned 32-bit integer 2D vector.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct UVec2 {
+    pub x: u32,
+    pub y: u32,
+}
+
+impl UVec2 {
+    /// Full constructor.
+    pub fn new(x: u32, y: u32) -> Self {
+        UVec2 { x, y }
+    }
+
+    /// Zero constructor.
+    pub fn zero() -> Self {
+        Default::default()
+    }
+}
+
+impl fmt::Display for UVec2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", (self.x, self.y))
+    }
+}
+
+/// Unsigned 32-bit integer 3D vector.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct UVec3 {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
+}
+
+impl UVec3 {
+    /// Full constructor.
+    pub fn new(x: u32, y: u32, z: u32) -> Self {
+        UVec3 { x, y, z }
+    }
+
+    /// Zero constructor.
+    pub fn zero() -> Self {
+        Default::default()
+    }
+}
+
+impl fmt::Display for UVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", (self.x, self.y, self.z))
+    }
+}
+
+/// Unsigned 32-bit integer 4D vector.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct UVec4 {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
+    pub w: u32,
+}
+
+impl UVec4 {
+    /// Full constructor.
+    pub fn new(x: u32, y: u32, z: u32, w: u32) -> Self {
+        UVec4 { x, y, z, w }
+    }
+
+    /// Zero constructor.
+    pub fn zero() -> Self {
+        Default::default()
+    }
+}
+
+impl fmt::Display for UVec4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", (self.x, self.y, self.z, self.w))
+    }
+}
+
+macro_rules! impl_ivector {
+    ($self:ty, $base:ty, $inner:ty, $array:ty) => {
+        impl ops::Add<$self> for $self {
+            type Output = $self;
+            fn add(self, rhs: $self) -> Self::Output {
+                let a: &$inner = self.as_ref().into();
+                let b: &$inner = rhs.as_ref().into();
+                let v: $array = (*a + *b).into();
+                v.into()
+            }
+        }
+
+        impl ops::Sub<$self> for $self {
+            type Output = $self;
+            fn sub(self, rhs: $self) -> Self::Output {
+                let a: &$inner = self.as_ref().into();
+                let b: &$inner = rhs.as_ref().into();
+                let v: $array = (*a - *b).into();
+                v.into()
+            }
+        }
+
+        impl ops::Mul<$base> for $self {
+            type Output = $self;
+            fn mul(self, arg: $base) -> Self::Output {
+                let a: &$inner = self.as_ref().into();
+                let v: $array = (*a * arg).into();
+                v.into()
+            }
+        }
+
+        impl ops::Mul<$self> for $self {
+            type Output = $self;
+            fn mul(self, rhs: $self) -> Self::Output {
+                use cgmath::ElementWise;
+                let a: &$inner = self.as_ref().into();
+                let b: &$inner = rhs.as_ref().into();
+                let v: $array = (*a).mul_element_wise(*b).into();
+                v.into()
+            }
+        }
+
+        impl AsRef<$array> for $self {
+            fn as_ref(&self) -> &$array {
+                unsafe {
+                    mem::transmute(self)
+                }
+            }
+        }
+
+        impl From<$array> for $self {
+            fn from(array: $array) -> Self {
+                unsafe {
+                    mem::transmute(array)
+                }
+            }
+        }
+
+        impl Into<$array> for $self {
+            fn into(self) -> $array {
+                unsafe {
+                    mem::transmute(self)
+                }
+            }
+        }
+    };
+}
+
+impl_ivector!(IVec2, i32, cgmath::Vector2<i32>, [i32; 2]);
+impl_ivector!(IVec3, i32, cgmath::Vector3<i32>, [i32; 3]);
+impl_ivector!(IVec4, i32, cgmath::Vector4<i32>, [i32; 4]);
+
+impl_ivector!(UVec2, u32, cgmath::Vector2<u32>, [u32; 2]);
+impl_ivector!(UVec3, u32, cgmath::Vector3<u32>, [u32; 3]);
+impl_ivector!(UVec4, u32, cgmath::Vector4<u32>, [u32; 4]);
+
+macro_rules! impl_vector_consts2 {
+    ($self:ty, $base:ty, $zero:expr, $one:expr) => {
+        impl $self {
+            /// The zero vector.
+            pub const ZERO: $self = Self { x: $zero, y: $zero };
+            /// The vector with every lane set to one.
+            pub const ONE: $self = Self { x: $one, y: $one };
+            /// The positive x axis.
+            pub const X: $self = Self { x: $one, y: $zero };
+            /// The positive y axis.
+            pub const Y: $self = Self { x: $zero, y: $one };
+            /// The unit axes in order.
+            pub const AXES: [$self; 2] = [Self::X, Self::Y];
+
+            /// Constructs a vector with every lane set to `v`.
+            pub fn splat(v: $base) -> Self {
+                Self { x: v, y: v }
+            }
+        }
+    };
+}
+
+macro_rules! impl_vector_consts3 {
+    ($self:ty, $base:ty, $zero:expr, $one:expr) => {
+        impl $self {
+            /// The zero vector.
+            pub const ZERO: $self = Self { x: $zero, y: $zero, z: $zero };
+            /// The vector with every lane set to one.
+            pub const ONE: $self = Self { x: $one, y: $one, z: $one };
+            /// The positive x axis.
+            pub const X: $self = Self { x: $one, y: $zero, z: $zero };
+            /// The positive y axis.
+            pub const Y: $self = Self { x: $zero, y: $one, z: $zero };
+            /// The positive z axis.
+            pub const Z: $self = Self { x: $zero, y: $zero, z: $one };
+            /// The unit axes in order.
+            pub const AXES: [$self; 3] = [Self::X, Self::Y, Self::Z];
+
+            /// Constructs a vector with every lane set to `v`.
+            pub fn splat(v: $base) -> Self {
+                Self { x: v, y: v, z: v }
+            }
+        }
+    };
+}
+
+macro_rules! impl_vector_consts4 {
+    ($self:ty, $base:ty, $zero:expr, $one:expr) => {
+        impl $self {
+            /// The zero vector.
+            pub const ZERO: $self = Self { x: $zero, y: $zero, z: $zero, w: $zero };
+            /// The vector with every lane set to one.
+            pub const ONE: $self = Self { x: $one, y: $one, z: $one, w: $one };
+            /// The positive x axis.
+            pub const X: $self = Self { x: $one, y: $zero, z: $zero, w: $zero };
+            /// The positive y axis.
+            pub const Y: $self = Self { x: $zero, y: $one, z: $zero, w: $zero };
+            /// The positive z axis.
+            pub const Z: $self = Self { x: $zero, y: $zero, z: $one, w: $zero };
+            /// The positive w axis.
+            pub const W: $self = Self { x: $zero, y: $zero, z: $zero, w: $one };
+            /// The unit axes in order.
+            pub const AXES: [$self; 4] = [Self::X, Self::Y, Self::Z, Self::W];
+
+            /// Constructs a vector with every lane set to `v`.
+            pub fn splat(v: $base) -> Self {
+                Self { x: v, y: v, z: v, w: v }
+            }
+        }
+    };
+}
+
+impl_vector_consts2!(Vec2, f32, 0.0, 1.0);
+impl_vector_consts3!(Vec3, f32, 0.0, 1.0);
+impl_vector_consts4!(Vec4, f32, 0.0, 1.0);
+
+impl_vector_consts2!(DVec2, f64, 0.0, 1.0);
+impl_vector_consts3!(DVec3, f64, 0.0, 1.0);
+impl_vector_consts4!(DVec4, f64, 0.0, 1.0);
+
+impl_vector_consts2!(IVec2, i32, 0, 1);
+impl_vector_consts3!(IVec3, i32, 0, 1);
+impl_vector_consts4!(IVec4, i32, 0, 1);
+
+impl_vector_consts2!(UVec2, u32, 0, 1);
+impl_vector_consts3!(UVec3, u32, 0, 1);
+impl_vector_consts4!(UVec4, u32, 0, 1);
+
+macro_rules! impl_mint_vector2 {
+    ($self:ty, $base:ty) => {
+        #[cfg(feature = "mint")]
+        impl From<mint::Vector2<$base>> for $self {
+            fn from(v: mint::Vector2<$base>) -> Self {
+                Self::new(v.x, v.y)
+            }
+        }
+
+        #[cfg(feature = "mint")]
+        impl From<$self> for mint::Vector2<$base> {
+            fn from(v: $self) -> Self {
+                mint::Vector2 { x: v.x, y: v.y }
+            }
+        }
+    };
+}
+
+macro_rules! impl_mint_vector3 {
+    ($self:ty, $base:ty) => {
+        #[cfg(feature = "mint")]
+        impl From<mint::Vector3<$base>> for $self {
+            fn from(v: mint::Vector3<$base>) -> Self {
+                Self::new(v.x, v.y, v.z)
+            }
+        }
+
+        #[cfg(feature = "mint")]
+        impl From<$self> for mint::Vector3<$base> {
+            fn from(v: $self) -> Self {
+                mint::Vector3 { x: v.x, y: v.y, z: v.z }
+            }
+        }
+    };
+}
+
+macro_rules! impl_mint_vector4 {
+    ($self:ty, $base:ty) => {
+        #[cfg(feature = "mint")]
+        impl From<mint::Vector4<$base>> for $self {
+            fn from(v: mint::Vector4<$base>) -> Self {
+                Self::new(v.x, v.y, v.z, v.w)
+            }
+        }
+
+        #[cfg(feature = "mint")]
+        impl From<$self> for mint::Vector4<$base> {
+            fn from(v: $self) -> Self {
+                mint::Vector4 { x: v.x, y: v.y, z: v.z, w: v.w }
+            }
+        }
+    };
+}
+
+impl_mint_vector2!(Vec2, f32);
+impl_mint_vector3!(Vec3, f32);
+impl_mint_vector4!(Vec4, f32);
+
+impl_mint_vector2!(DVec2, f64);
+impl_mint_vector3!(DVec3, f64);
+impl_mint_vector4!(DVec4, f64);